@@ -3,4 +3,172 @@ use crate::option::MyOption;
 pub trait MyIterator {
     type Item;
     fn next(&mut self) -> MyOption<Self::Item>;
+
+    // wraps this iterator, applying `f` to each item as it's produced
+    fn map<U, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> U,
+    {
+        Map { iter: self, f }
+    }
+
+    // wraps this iterator, yielding only the items for which `predicate` returns true
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter {
+            iter: self,
+            predicate,
+        }
+    }
+
+    // wraps this iterator, yielding at most `n` items before stopping for good
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take { iter: self, n }
+    }
+
+    // consumes the iterator, folding every item into an accumulator
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        while let MyOption::Some(item) = self.next() {
+            accum = f(accum, item);
+        }
+        accum
+    }
+}
+
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, U> MyIterator for Map<I, F>
+where
+    I: MyIterator,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> MyOption<U> {
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> MyIterator for Filter<I, P>
+where
+    I: MyIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> MyOption<I::Item> {
+        while let MyOption::Some(item) = self.iter.next() {
+            if (self.predicate)(&item) {
+                return MyOption::Some(item);
+            }
+        }
+        MyOption::None
+    }
+}
+
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I: MyIterator> MyIterator for Take<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> MyOption<I::Item> {
+        if self.n == 0 {
+            return MyOption::None;
+        }
+        self.n -= 1;
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod iter_test {
+    use super::MyIterator;
+    use crate::option::MyOption;
+
+    // a bare-bones MyIterator over a Vec, used to exercise the adapters in isolation
+    struct Count {
+        items: Vec<u32>,
+    }
+
+    impl MyIterator for Count {
+        type Item = u32;
+
+        fn next(&mut self) -> MyOption<u32> {
+            if self.items.is_empty() {
+                MyOption::None
+            } else {
+                MyOption::Some(self.items.remove(0))
+            }
+        }
+    }
+
+    fn count(items: &[u32]) -> Count {
+        Count {
+            items: items.to_vec(),
+        }
+    }
+
+    #[test]
+    fn map_test() {
+        let mut it = count(&[1, 2, 3]).map(|x| x * 2);
+        assert_eq!(it.next(), MyOption::Some(2));
+        assert_eq!(it.next(), MyOption::Some(4));
+        assert_eq!(it.next(), MyOption::Some(6));
+        assert_eq!(it.next(), MyOption::None);
+    }
+
+    #[test]
+    fn filter_test() {
+        let mut it = count(&[1, 2, 3, 4]).filter(|&x| x % 2 == 0);
+        assert_eq!(it.next(), MyOption::Some(2));
+        assert_eq!(it.next(), MyOption::Some(4));
+        assert_eq!(it.next(), MyOption::None);
+    }
+
+    #[test]
+    fn take_test() {
+        let mut it = count(&[1, 2, 3]).take(2);
+        assert_eq!(it.next(), MyOption::Some(1));
+        assert_eq!(it.next(), MyOption::Some(2));
+        assert_eq!(it.next(), MyOption::None);
+    }
+
+    #[test]
+    fn fold_test() {
+        let sum = count(&[1, 2, 3, 4]).fold(0, |acc, x| acc + x);
+        assert_eq!(sum, 10);
+    }
+
+    #[test]
+    fn chained_adapters_test() {
+        let sum = count(&[1, 2, 3, 4, 5])
+            .filter(|&x| x % 2 == 1)
+            .map(|x| x * 10)
+            .take(2)
+            .fold(0, |acc, x| acc + x);
+        assert_eq!(sum, 40);
+    }
 }
@@ -0,0 +1,2 @@
+pub mod iter;
+pub mod option;
@@ -1,8 +1,5 @@
-// mod iter;
-// mod option;
-
-// use iter::MyIterator;
-// use option::MyOption;
+use kpb::iter::MyIterator;
+use kpb::option::MyOption;
 
 fn main() {
     let a = [0, 1, 2];
@@ -11,4 +8,10 @@ fn main() {
 
     assert_eq!(iter.next(), Some(&2));
     assert_eq!(iter.next(), None);
+
+    let x = MyOption::Some(2);
+    let mut iter = x.iter().filter(|&&n| n > 1);
+
+    assert_eq!(iter.next(), MyOption::Some(&2));
+    assert_eq!(iter.next(), MyOption::None);
 }
@@ -1,5 +1,7 @@
 // replicate the std::option module
 
+use crate::iter::MyIterator;
+
 // This is a separate function to reduce the code size of .expect() itself.
 #[inline(never)]
 #[cold]
@@ -8,14 +10,6 @@ fn expect_failed(msg: &str) -> ! {
     panic!("{}", msg)
 }
 
-// This is a separate function to reduce the code size of .expect_none() itself.
-#[inline(never)]
-#[cold]
-#[track_caller]
-fn expect_none_failed(msg: &str, value: &dyn std::fmt::Debug) -> ! {
-    panic!("{}: {:?}", msg, value)
-}
-
 #[derive(Debug, PartialEq)]
 pub enum MyOption<T> {
     None,
@@ -83,7 +77,7 @@ impl<T> MyOption<T> {
     pub fn unwrap(self) -> T {
         match self {
             MyOption::Some(t) => t,
-            MyOption::None => panic!("called `MyOption::unwrap()` on a `None` value"),
+            MyOption::None => expect_failed("called `MyOption::unwrap()` on a `None` value"),
         }
     }
 
@@ -125,7 +119,7 @@ impl<T> MyOption<T> {
 
     pub fn and<U>(self, optb: MyOption<U>) -> MyOption<U> {
         match self {
-            MyOption::Some(val) => optb,
+            MyOption::Some(_) => optb,
             MyOption::None => MyOption::None,
         }
     }
@@ -174,6 +168,285 @@ impl<T> MyOption<T> {
             _ => MyOption::None,
         }
     }
+
+    // takes the value out of the option, leaving a `None` in its place
+    pub fn take(&mut self) -> MyOption<T> {
+        std::mem::replace(self, MyOption::None)
+    }
+
+    // replaces the actual value in the option by the value given in parameter,
+    // returning the old value if present, leaving a `Some` in its place
+    pub fn replace(&mut self, value: T) -> MyOption<T> {
+        std::mem::replace(self, MyOption::Some(value))
+    }
+
+    // inserts `value` into the option, then returns a mutable reference to it
+    //
+    // if the option already contains a value, the old value is dropped
+    pub fn insert(&mut self, value: T) -> &mut T {
+        *self = MyOption::Some(value);
+        match self {
+            MyOption::Some(t) => t,
+            MyOption::None => unreachable!(),
+        }
+    }
+
+    // inserts `value` into the option if it is `None`, then returns a mutable
+    // reference to the contained value
+    pub fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.get_or_insert_with(|| value)
+    }
+
+    // inserts a value computed from `f` into the option if it is `None`, then
+    // returns a mutable reference to the contained value
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if let MyOption::None = self {
+            *self = MyOption::Some(f());
+        }
+        match self {
+            MyOption::Some(t) => t,
+            MyOption::None => unreachable!(),
+        }
+    }
+
+    // returns an iterator over the possibly-contained value, borrowed
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { inner: self.as_ref() }
+    }
+
+    // returns an iterator over the possibly-contained value, mutably borrowed
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.as_mut(),
+        }
+    }
+
+    // returns a consuming iterator over the possibly-contained value
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self }
+    }
+
+    // zips `self` with another `MyOption`
+    //
+    // if `self` is `Some(t)` and `other` is `Some(u)`, this returns `Some((t, u))`.
+    // otherwise, `None` is returned.
+    pub fn zip<U>(self, other: MyOption<U>) -> MyOption<(T, U)> {
+        match (self, other) {
+            (MyOption::Some(t), MyOption::Some(u)) => MyOption::Some((t, u)),
+            _ => MyOption::None,
+        }
+    }
+}
+
+impl<T, E> MyOption<Result<T, E>> {
+    // transposes a `MyOption` of a `Result` into a `Result` of a `MyOption`
+    //
+    // `None` will be mapped to `Ok(None)`. `Some(Ok(_))` and `Some(Err(_))` will be
+    // mapped to `Ok(Some(_))` and `Err(_)`.
+    pub fn transpose(self) -> Result<MyOption<T>, E> {
+        match self {
+            MyOption::Some(Ok(t)) => Ok(MyOption::Some(t)),
+            MyOption::Some(Err(e)) => Err(e),
+            MyOption::None => Ok(MyOption::None),
+        }
+    }
+}
+
+impl<T> MyOption<MyOption<T>> {
+    // converts from `MyOption<MyOption<T>>` to `MyOption<T>`, collapsing one level
+    // of nesting
+    pub fn flatten(self) -> MyOption<T> {
+        match self {
+            MyOption::Some(inner) => inner,
+            MyOption::None => MyOption::None,
+        }
+    }
+}
+
+impl<A, B> MyOption<(A, B)> {
+    // unzips an option containing a tuple of two values into a tuple of two options
+    //
+    // `Some((a, b))` is unzipped to `(Some(a), Some(b))`, and `None` is unzipped
+    // to `(None, None)`.
+    pub fn unzip(self) -> (MyOption<A>, MyOption<B>) {
+        match self {
+            MyOption::Some((a, b)) => (MyOption::Some(a), MyOption::Some(b)),
+            MyOption::None => (MyOption::None, MyOption::None),
+        }
+    }
+}
+
+// iterator over a borrowed MyOption, yielding at most one &T
+pub struct Iter<'a, T> {
+    inner: MyOption<&'a T>,
+}
+
+impl<'a, T> MyIterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> MyOption<&'a T> {
+        std::mem::replace(&mut self.inner, MyOption::None)
+    }
+}
+
+// iterator over a mutably borrowed MyOption, yielding at most one &mut T
+pub struct IterMut<'a, T> {
+    inner: MyOption<&'a mut T>,
+}
+
+impl<'a, T> MyIterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> MyOption<&'a mut T> {
+        std::mem::replace(&mut self.inner, MyOption::None)
+    }
+}
+
+// consuming iterator over a MyOption, yielding at most one T
+pub struct IntoIter<T> {
+    inner: MyOption<T>,
+}
+
+impl<T> MyIterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> MyOption<T> {
+        std::mem::replace(&mut self.inner, MyOption::None)
+    }
+}
+
+// error returned when a Pack impl fails to decode a byte slice
+#[derive(Debug, PartialEq, Eq)]
+pub enum PackError {
+    // slice passed to unpack_from_slice was not exactly `LEN` bytes long
+    // (1 + T::LEN for CMyOption<T>)
+    InvalidLength { expected: usize, found: usize },
+    // leading tag byte was neither 0 (None) nor 1 (Some)
+    InvalidTag(u8),
+}
+
+// types that can be packed into, and unpacked from, a fixed-size byte buffer
+pub trait Pack: Sized {
+    const LEN: usize;
+
+    fn pack_into_slice(&self, dst: &mut [u8]);
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError>;
+}
+
+// FFI-stable, repr(C) counterpart to MyOption, for use across a C ABI boundary
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CMyOption<T> {
+    None,
+    Some(T),
+}
+
+impl<T> CMyOption<T> {
+    pub const fn is_some(&self) -> bool {
+        std::matches!(self, CMyOption::Some(_))
+    }
+
+    pub const fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    pub const fn as_ref(&self) -> CMyOption<&T> {
+        match self {
+            CMyOption::Some(ref t) => CMyOption::Some(t),
+            CMyOption::None => CMyOption::None,
+        }
+    }
+
+    pub fn as_mut(&mut self) -> CMyOption<&mut T> {
+        match self {
+            CMyOption::Some(ref mut t) => CMyOption::Some(t),
+            CMyOption::None => CMyOption::None,
+        }
+    }
+
+    pub fn map<U, F>(self, f: F) -> CMyOption<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            CMyOption::Some(t) => CMyOption::Some(f(t)),
+            CMyOption::None => CMyOption::None,
+        }
+    }
+}
+
+impl<T> std::ops::Deref for CMyOption<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            CMyOption::Some(t) => t,
+            CMyOption::None => panic!("called `Deref::deref()` on a `None` value"),
+        }
+    }
+}
+
+impl<T> std::ops::DerefMut for CMyOption<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match self {
+            CMyOption::Some(t) => t,
+            CMyOption::None => panic!("called `DerefMut::deref_mut()` on a `None` value"),
+        }
+    }
+}
+
+impl<T> From<MyOption<T>> for CMyOption<T> {
+    fn from(opt: MyOption<T>) -> Self {
+        match opt {
+            MyOption::Some(t) => CMyOption::Some(t),
+            MyOption::None => CMyOption::None,
+        }
+    }
+}
+
+impl<T> From<CMyOption<T>> for MyOption<T> {
+    fn from(opt: CMyOption<T>) -> Self {
+        match opt {
+            CMyOption::Some(t) => MyOption::Some(t),
+            CMyOption::None => MyOption::None,
+        }
+    }
+}
+
+impl<T: Pack> Pack for CMyOption<T> {
+    const LEN: usize = 1 + T::LEN;
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        match self {
+            CMyOption::Some(t) => {
+                dst[0] = 1;
+                t.pack_into_slice(&mut dst[1..]);
+            }
+            CMyOption::None => {
+                dst[0] = 0;
+                dst[1..].fill(0);
+            }
+        }
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+        if src.len() != Self::LEN {
+            return Err(PackError::InvalidLength {
+                expected: Self::LEN,
+                found: src.len(),
+            });
+        }
+        match src[0] {
+            0 => Ok(CMyOption::None),
+            1 => Ok(CMyOption::Some(T::unpack_from_slice(&src[1..])?)),
+            tag => Err(PackError::InvalidTag(tag)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -308,6 +581,80 @@ pub mod options_test {
         assert_eq!(x.is_some(), false);
     }
 
+    #[test]
+    fn take_test() {
+        let mut x = MyOption::Some(2);
+        let y = x.take();
+        assert_eq!(x, MyOption::None);
+        assert_eq!(y, MyOption::Some(2));
+
+        let mut x: MyOption<u32> = MyOption::None;
+        let y = x.take();
+        assert_eq!(x, MyOption::None);
+        assert_eq!(y, MyOption::None);
+    }
+
+    #[test]
+    fn replace_test() {
+        let mut x = MyOption::Some(2);
+        let old = x.replace(5);
+        assert_eq!(x, MyOption::Some(5));
+        assert_eq!(old, MyOption::Some(2));
+
+        let mut x: MyOption<u32> = MyOption::None;
+        let old = x.replace(3);
+        assert_eq!(x, MyOption::Some(3));
+        assert_eq!(old, MyOption::None);
+    }
+
+    #[test]
+    fn insert_test() {
+        let mut opt = MyOption::None;
+        let val = opt.insert(1);
+        assert_eq!(*val, 1);
+        assert_eq!(opt, MyOption::Some(1));
+
+        let val = opt.insert(2);
+        assert_eq!(*val, 2);
+        *val = 3;
+        assert_eq!(opt, MyOption::Some(3));
+    }
+
+    #[test]
+    fn get_or_insert_test() {
+        let mut x: MyOption<u32> = MyOption::None;
+        {
+            let y = x.get_or_insert(5);
+            assert_eq!(y, &5);
+            *y = 7;
+        }
+        assert_eq!(x, MyOption::Some(7));
+
+        let mut x = MyOption::Some(1);
+        let y = x.get_or_insert(5);
+        assert_eq!(y, &1);
+    }
+
+    #[test]
+    fn get_or_insert_with_test() {
+        let mut x: MyOption<u32> = MyOption::None;
+        {
+            let y = x.get_or_insert_with(|| 5);
+            assert_eq!(y, &5);
+            *y = 7;
+        }
+        assert_eq!(x, MyOption::Some(7));
+
+        let mut called = false;
+        let mut x = MyOption::Some(1);
+        let y = x.get_or_insert_with(|| {
+            called = true;
+            5
+        });
+        assert_eq!(y, &1);
+        assert!(!called);
+    }
+
     #[test]
     fn map_test() {
         let maybe_some_string = MyOption::Some(String::from("Hello, Map Test!"));
@@ -357,6 +704,40 @@ pub mod options_test {
         assert_eq!(x, MyOption::Some(42));
     }
 
+    #[test]
+    fn iter_test() {
+        use crate::iter::MyIterator;
+
+        let x = MyOption::Some(4);
+        let mut it = x.iter();
+        assert_eq!(it.next(), MyOption::Some(&4));
+        assert_eq!(it.next(), MyOption::None);
+
+        let x: MyOption<u32> = MyOption::None;
+        assert_eq!(x.iter().next(), MyOption::None);
+    }
+
+    #[test]
+    fn iter_mut_test() {
+        use crate::iter::MyIterator;
+
+        let mut x = MyOption::Some(4);
+        if let MyOption::Some(v) = x.iter_mut().next() {
+            *v = 42;
+        }
+        assert_eq!(x, MyOption::Some(42));
+    }
+
+    #[test]
+    fn into_iter_test() {
+        use crate::iter::MyIterator;
+
+        let x = MyOption::Some(4);
+        let mut it = x.into_iter();
+        assert_eq!(it.next(), MyOption::Some(4));
+        assert_eq!(it.next(), MyOption::None);
+    }
+
     #[test]
     fn unwrap_test() {
         let x = MyOption::Some("air");
@@ -391,4 +772,138 @@ pub mod options_test {
         let x: MyOption<&str> = MyOption::None;
         assert_eq!(x.ok_or(0), Err(0));
     }
+
+    #[test]
+    fn transpose_test() {
+        #[derive(Debug, PartialEq)]
+        struct SomeErr;
+
+        let x: Result<MyOption<i32>, SomeErr> = Ok(MyOption::Some(5));
+        let y: MyOption<Result<i32, SomeErr>> = MyOption::Some(Ok(5));
+        assert_eq!(x, y.transpose());
+
+        let x: Result<MyOption<i32>, SomeErr> = Err(SomeErr);
+        let y: MyOption<Result<i32, SomeErr>> = MyOption::Some(Err(SomeErr));
+        assert_eq!(x, y.transpose());
+
+        let x: Result<MyOption<i32>, SomeErr> = Ok(MyOption::None);
+        let y: MyOption<Result<i32, SomeErr>> = MyOption::None;
+        assert_eq!(x, y.transpose());
+    }
+
+    #[test]
+    fn flatten_test() {
+        let x: MyOption<MyOption<u32>> = MyOption::Some(MyOption::Some(6));
+        assert_eq!(x.flatten(), MyOption::Some(6));
+
+        let x: MyOption<MyOption<u32>> = MyOption::Some(MyOption::None);
+        assert_eq!(x.flatten(), MyOption::None);
+
+        let x: MyOption<MyOption<u32>> = MyOption::None;
+        assert_eq!(x.flatten(), MyOption::None);
+    }
+
+    #[test]
+    fn zip_test() {
+        let x = MyOption::Some(1);
+        let y = MyOption::Some("hi");
+        assert_eq!(x.zip(y), MyOption::Some((1, "hi")));
+
+        let x = MyOption::Some(1);
+        let z: MyOption<&str> = MyOption::None;
+        assert_eq!(x.zip(z), MyOption::None);
+    }
+
+    #[test]
+    fn unzip_test() {
+        let x = MyOption::Some((1, "hi"));
+        assert_eq!(x.unzip(), (MyOption::Some(1), MyOption::Some("hi")));
+
+        let x: MyOption<(u32, &str)> = MyOption::None;
+        assert_eq!(x.unzip(), (MyOption::None, MyOption::None));
+    }
+}
+
+#[cfg(test)]
+mod pack_test {
+    use super::{CMyOption, MyOption, Pack, PackError};
+
+    impl Pack for u32 {
+        const LEN: usize = 4;
+
+        fn pack_into_slice(&self, dst: &mut [u8]) {
+            dst[..4].copy_from_slice(&self.to_le_bytes());
+        }
+
+        fn unpack_from_slice(src: &[u8]) -> Result<Self, PackError> {
+            let bytes: [u8; 4] = src.try_into().map_err(|_| PackError::InvalidLength {
+                expected: 4,
+                found: src.len(),
+            })?;
+            Ok(u32::from_le_bytes(bytes))
+        }
+    }
+
+    #[test]
+    fn from_and_into_my_option() {
+        let some: MyOption<u32> = MyOption::Some(7);
+        let c: CMyOption<u32> = some.into();
+        assert_eq!(c, CMyOption::Some(7));
+
+        let back: MyOption<u32> = c.into();
+        assert_eq!(back, MyOption::Some(7));
+
+        let none: CMyOption<u32> = CMyOption::from(MyOption::<u32>::None);
+        assert_eq!(none, CMyOption::None);
+    }
+
+    #[test]
+    fn deref_and_deref_mut() {
+        let mut x = CMyOption::Some(41);
+        assert_eq!(*x, 41);
+        *x += 1;
+        assert_eq!(*x, 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn deref_none_panics() {
+        let x: CMyOption<u32> = CMyOption::None;
+        let _ = *x;
+    }
+
+    #[test]
+    fn pack_round_trip() {
+        let mut buf = [0u8; CMyOption::<u32>::LEN];
+
+        let some = CMyOption::Some(0xdead_beef_u32);
+        some.pack_into_slice(&mut buf);
+        assert_eq!(CMyOption::unpack_from_slice(&buf).unwrap(), some);
+
+        let none: CMyOption<u32> = CMyOption::None;
+        none.pack_into_slice(&mut buf);
+        assert_eq!(buf, [0, 0, 0, 0, 0]);
+        assert_eq!(CMyOption::unpack_from_slice(&buf).unwrap(), none);
+    }
+
+    #[test]
+    fn unpack_rejects_bad_length() {
+        let buf = [0u8; 2];
+        assert_eq!(
+            CMyOption::<u32>::unpack_from_slice(&buf),
+            Err(PackError::InvalidLength {
+                expected: 5,
+                found: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn unpack_rejects_bad_tag() {
+        let buf = [2u8, 0, 0, 0, 0];
+        assert_eq!(
+            CMyOption::<u32>::unpack_from_slice(&buf),
+            Err(PackError::InvalidTag(2))
+        );
+    }
 }